@@ -0,0 +1,93 @@
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use napi_derive::napi;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+/// How many bytes to sniff from the start of a file when deciding whether
+/// it looks binary.
+const SNIFF_LEN: usize = 8192;
+
+/// How to handle a file that looks binary instead of emitting it with an
+/// empty `content` and a generic read error.
+#[napi]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum BinaryMode {
+    /// Drop binary files from `file_contents` entirely.
+    Skip,
+    /// Emit a `[Binary file, N bytes]` placeholder in place of the content.
+    Placeholder,
+    /// Emit the base64-encoded bytes as `content`.
+    Base64,
+}
+
+/// Reads the first `SNIFF_LEN` bytes of `path` and classifies it as binary
+/// if it contains a NUL byte or an implausibly high ratio of non-text
+/// control bytes.
+pub fn looks_binary(path: &Path) -> bool {
+    let Ok(mut file) = fs::File::open(path) else {
+        return false;
+    };
+
+    let mut buf = vec![0u8; SNIFF_LEN];
+    let n = match file.read(&mut buf) {
+        Ok(n) => n,
+        Err(_) => return false,
+    };
+    let buf = &buf[..n];
+
+    if buf.is_empty() {
+        return false;
+    }
+
+    if buf.contains(&0) {
+        return true;
+    }
+
+    let control_bytes = buf
+        .iter()
+        .filter(|&&b| b < 0x09 || (b > 0x0d && b < 0x20))
+        .count();
+
+    (control_bytes as f64 / buf.len() as f64) > 0.3
+}
+
+/// Reads `path` in full and base64-encodes its bytes.
+pub fn read_base64(path: &Path) -> std::io::Result<String> {
+    let bytes = fs::read(path)?;
+    Ok(STANDARD.encode(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn temp_file(name: &str, contents: &[u8]) -> std::path::PathBuf {
+        let mut path = std::env::temp_dir();
+        path.push(format!(
+            "lingest-binary-test-{}-{}-{}",
+            std::process::id(),
+            name,
+            COUNTER.fetch_add(1, Ordering::SeqCst)
+        ));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn nul_byte_is_classified_as_binary() {
+        let path = temp_file("nul", b"hello\0world");
+        assert!(looks_binary(&path));
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn multibyte_utf8_text_is_not_classified_as_binary() {
+        let path = temp_file("utf8", "héllo wörld, 日本語のテキストです".as_bytes());
+        assert!(!looks_binary(&path));
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Patterns parsed out of a `lingest.config` file (and anything it
+/// `%include`s), ready to be merged with the `ignore_globs`/`include_globs`
+/// already on `LingestOptions`.
+#[derive(Default)]
+pub struct ParsedConfig {
+    pub ignore_globs: Vec<String>,
+    pub include_globs: Vec<String>,
+    pub options: Vec<(String, String)>,
+}
+
+enum Section {
+    None,
+    Ignore,
+    Include,
+    Options,
+}
+
+/// Loads `path` (an INI-style config with `[ignore]`, `[include]`, and
+/// `[options]` sections, one glob per line), recursively merging any
+/// `%include <path>` directives and applying `%unset <glob>` overrides.
+///
+/// `%include` paths are resolved relative to the including file. Cycle
+/// detection guards against a file transitively including itself.
+pub fn load(path: &Path) -> ParsedConfig {
+    let mut config = ParsedConfig::default();
+    let mut seen = HashSet::new();
+    load_into(path, &mut config, &mut seen);
+    config
+}
+
+fn load_into(path: &Path, config: &mut ParsedConfig, seen: &mut HashSet<PathBuf>) {
+    let canonical = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    if !seen.insert(canonical) {
+        return;
+    }
+
+    let Ok(contents) = fs::read_to_string(path) else {
+        return;
+    };
+
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let mut section = Section::None;
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+
+        if let Some(directive) = line.strip_prefix("%include ") {
+            let include_path = dir.join(directive.trim());
+            load_into(&include_path, config, seen);
+            continue;
+        }
+
+        if let Some(pattern) = line.strip_prefix("%unset ") {
+            unset(config, pattern.trim());
+            continue;
+        }
+
+        if line.starts_with('[') && line.ends_with(']') {
+            section = match &line[1..line.len() - 1] {
+                "ignore" => Section::Ignore,
+                "include" => Section::Include,
+                "options" => Section::Options,
+                _ => Section::None,
+            };
+            continue;
+        }
+
+        match section {
+            Section::Ignore => config.ignore_globs.push(line.to_string()),
+            Section::Include => config.include_globs.push(line.to_string()),
+            Section::Options => {
+                if let Some((key, value)) = line.split_once('=') {
+                    config
+                        .options
+                        .push((key.trim().to_string(), value.trim().to_string()));
+                }
+            }
+            Section::None => {}
+        }
+    }
+}
+
+/// Looks up a `key = value` pair parsed from the `[options]` section.
+pub fn option<'a>(options: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    options
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
+}
+
+fn unset(config: &mut ParsedConfig, pattern: &str) {
+    config.ignore_globs.retain(|p| p != pattern);
+    config.include_globs.retain(|p| p != pattern);
+}
+
+/// Merges parsed config patterns with the explicit options already on
+/// `LingestOptions`. Explicit options win, so config patterns are prepended
+/// rather than appended (a later, explicit `%unset` in the config can still
+/// remove an earlier config entry, but nothing in the config can remove
+/// what the caller passed in explicitly).
+pub fn merge(config_patterns: Vec<String>, explicit: &[String]) -> Vec<String> {
+    let mut merged = config_patterns;
+    for pattern in explicit {
+        if !merged.iter().any(|p| p == pattern) {
+            merged.push(pattern.clone());
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let mut dir = std::env::temp_dir();
+        dir.push(format!(
+            "lingest-config-test-{}-{}-{}",
+            std::process::id(),
+            name,
+            COUNTER.fetch_add(1, Ordering::SeqCst)
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn self_include_cycle_terminates() {
+        let dir = temp_dir("self-cycle");
+        let path = dir.join("lingest.config");
+        fs::write(&path, "[ignore]\n*.log\n%include lingest.config\n").unwrap();
+
+        let config = load(&path);
+
+        assert_eq!(config.ignore_globs, vec!["*.log".to_string()]);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn mutual_include_cycle_terminates() {
+        let dir = temp_dir("mutual-cycle");
+        let a = dir.join("a.config");
+        let b = dir.join("b.config");
+        fs::write(&a, "[ignore]\nfrom_a\n%include b.config\n").unwrap();
+        fs::write(&b, "[ignore]\nfrom_b\n%include a.config\n").unwrap();
+
+        let config = load(&a);
+
+        assert_eq!(
+            config.ignore_globs,
+            vec!["from_a".to_string(), "from_b".to_string()]
+        );
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn unset_removes_an_included_pattern() {
+        let dir = temp_dir("unset");
+        let base = dir.join("base.config");
+        let child = dir.join("lingest.config");
+        fs::write(&base, "[ignore]\n*.log\n*.tmp\n").unwrap();
+        fs::write(
+            &child,
+            "%include base.config\n%unset *.log\n[ignore]\n*.bak\n",
+        )
+        .unwrap();
+
+        let config = load(&child);
+
+        assert_eq!(
+            config.ignore_globs,
+            vec!["*.tmp".to_string(), "*.bak".to_string()]
+        );
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
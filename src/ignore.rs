@@ -0,0 +1,307 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// A single compiled rule parsed out of a `.gitignore`/`.ignore` file.
+///
+/// The directory the owning ignore file lives in is kept on `IgnoreFile`
+/// (`dir`); anchored patterns are matched relative to it rather than the
+/// scan root.
+struct Pattern {
+    text: String,
+    anchored: bool,
+    directory_only: bool,
+    whitelist: bool,
+}
+
+impl Pattern {
+    fn parse(line: &str) -> Option<Pattern> {
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+
+        let mut text = line;
+        let whitelist = if let Some(rest) = text.strip_prefix('!') {
+            text = rest;
+            true
+        } else {
+            false
+        };
+
+        let directory_only = text.ends_with('/');
+        if directory_only {
+            text = &text[..text.len() - 1];
+        }
+
+        let anchored = text.starts_with('/') || text.contains('/');
+        let text = text.strip_prefix('/').unwrap_or(text).to_string();
+
+        if text.is_empty() {
+            return None;
+        }
+
+        Some(Pattern {
+            text,
+            anchored,
+            directory_only,
+            whitelist,
+        })
+    }
+
+    /// `relative` is the path of the candidate relative to this pattern's
+    /// ignore-file directory, using `/` separators.
+    fn matches(&self, relative: &str, is_dir: bool) -> bool {
+        if self.directory_only && !is_dir {
+            return false;
+        }
+
+        let glob_text = if self.anchored {
+            self.text.clone()
+        } else {
+            format!("**/{}", self.text)
+        };
+
+        glob::Pattern::new(&glob_text)
+            .map(|p| p.matches(relative))
+            .unwrap_or(false)
+    }
+}
+
+/// The set of rules contributed by a single `.gitignore`/`.ignore` file,
+/// scoped to the directory it was found in.
+struct IgnoreFile {
+    dir: PathBuf,
+    patterns: Vec<Pattern>,
+}
+
+impl IgnoreFile {
+    fn load(dir: &Path, file_name: &str) -> Option<IgnoreFile> {
+        let contents = fs::read_to_string(dir.join(file_name)).ok()?;
+        let patterns: Vec<Pattern> = contents.lines().filter_map(Pattern::parse).collect();
+        if patterns.is_empty() {
+            None
+        } else {
+            Some(IgnoreFile {
+                dir: dir.to_path_buf(),
+                patterns,
+            })
+        }
+    }
+}
+
+/// Hierarchical `.gitignore`/`.ignore` matcher, modeled on how watchexec
+/// loads ignore files while walking a tree: every directory between the
+/// scan root and a candidate path may contribute its own ignore file, and
+/// the deepest, last-listed matching pattern wins (so a child directory's
+/// whitelist rule can re-include something a parent ignored).
+pub struct Ignore {
+    root: PathBuf,
+    respect_gitignore: bool,
+    no_ignore: bool,
+    exclude_git_dir: bool,
+    cache: Mutex<HashMap<PathBuf, Option<std::sync::Arc<Vec<IgnoreFile>>>>>,
+}
+
+impl Ignore {
+    pub fn new(root: &Path, respect_gitignore: bool, no_ignore: bool, exclude_git_dir: bool) -> Ignore {
+        Ignore {
+            root: root.to_path_buf(),
+            respect_gitignore,
+            no_ignore,
+            exclude_git_dir,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns true if `path` should be excluded from the walk.
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        if self.no_ignore {
+            return false;
+        }
+
+        if self.exclude_git_dir {
+            if let Ok(relative) = path.strip_prefix(&self.root) {
+                if relative
+                    .components()
+                    .next()
+                    .map(|c| c.as_os_str() == ".git")
+                    .unwrap_or(false)
+                {
+                    return true;
+                }
+            }
+        }
+
+        if !self.respect_gitignore {
+            return false;
+        }
+
+        let parent = path.parent().unwrap_or(&self.root);
+        let dir = if parent.starts_with(&self.root) {
+            parent
+        } else {
+            // `path` is the scan root itself (or something outside it):
+            // never climb above `self.root` looking for ignore files.
+            self.root.as_path()
+        };
+        let stack = self.stack_for(dir);
+
+        for ignore_file in stack.iter().rev() {
+            let relative_to_file = match path.strip_prefix(&ignore_file.dir) {
+                Ok(r) => r.to_string_lossy().replace('\\', "/"),
+                Err(_) => continue,
+            };
+
+            for pattern in ignore_file.patterns.iter().rev() {
+                if pattern.matches(&relative_to_file, is_dir) {
+                    return !pattern.whitelist;
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Builds (and caches) the chain of ignore files that apply to
+    /// directory `dir`, ordered from the scan root down to `dir` itself.
+    fn stack_for(&self, dir: &Path) -> std::sync::Arc<Vec<IgnoreFile>> {
+        if let Some(cached) = self.cache.lock().unwrap().get(dir).cloned() {
+            if let Some(cached) = cached {
+                return cached;
+            }
+        }
+
+        let mut chain: Vec<&Path> = Vec::new();
+        let mut current = Some(dir);
+        while let Some(d) = current {
+            chain.push(d);
+            if d == self.root {
+                break;
+            }
+            current = d.parent();
+        }
+        chain.reverse();
+
+        let mut stack = Vec::new();
+        for d in chain {
+            if let Some(ignore_file) = IgnoreFile::load(d, ".gitignore") {
+                stack.push(ignore_file);
+            }
+            if let Some(ignore_file) = IgnoreFile::load(d, ".ignore") {
+                stack.push(ignore_file);
+            }
+        }
+
+        let stack = std::sync::Arc::new(stack);
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(dir.to_path_buf(), Some(stack.clone()));
+        stack
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    /// Creates a fresh, uniquely-named temp directory for a test to populate.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> TempDir {
+            let mut dir = std::env::temp_dir();
+            dir.push(format!(
+                "lingest-ignore-test-{}-{}-{}",
+                std::process::id(),
+                name,
+                COUNTER.fetch_add(1, Ordering::SeqCst)
+            ));
+            fs::create_dir_all(&dir).unwrap();
+            TempDir(dir)
+        }
+
+        fn path(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn child_whitelist_reincludes_parent_ignored_file() {
+        let root = TempDir::new("whitelist");
+        fs::write(root.path().join(".gitignore"), "*.log\n").unwrap();
+        fs::create_dir_all(root.path().join("sub")).unwrap();
+        fs::write(root.path().join("sub/.gitignore"), "!keep.log\n").unwrap();
+        fs::write(root.path().join("other.log"), "").unwrap();
+        fs::write(root.path().join("sub/other.log"), "").unwrap();
+        fs::write(root.path().join("sub/keep.log"), "").unwrap();
+
+        let ignore = Ignore::new(root.path(), true, false, true);
+
+        assert!(ignore.is_ignored(&root.path().join("other.log"), false));
+        assert!(ignore.is_ignored(&root.path().join("sub/other.log"), false));
+        assert!(!ignore.is_ignored(&root.path().join("sub/keep.log"), false));
+    }
+
+    #[test]
+    fn anchored_pattern_only_matches_its_own_directory() {
+        let root = TempDir::new("anchored");
+        fs::write(
+            root.path().join(".gitignore"),
+            "/anchored.txt\nnonanchored.txt\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.path().join("sub")).unwrap();
+        fs::write(root.path().join("anchored.txt"), "").unwrap();
+        fs::write(root.path().join("nonanchored.txt"), "").unwrap();
+        fs::write(root.path().join("sub/anchored.txt"), "").unwrap();
+        fs::write(root.path().join("sub/nonanchored.txt"), "").unwrap();
+
+        let ignore = Ignore::new(root.path(), true, false, true);
+
+        assert!(ignore.is_ignored(&root.path().join("anchored.txt"), false));
+        assert!(!ignore.is_ignored(&root.path().join("sub/anchored.txt"), false));
+        assert!(ignore.is_ignored(&root.path().join("nonanchored.txt"), false));
+        assert!(ignore.is_ignored(&root.path().join("sub/nonanchored.txt"), false));
+    }
+
+    #[test]
+    fn scan_root_itself_never_climbs_above_root() {
+        // A rule in `outer` (the scan root's *parent*) that would match
+        // the scan root's own name must never be consulted: the walk is
+        // clamped to the scan root, it does not climb the filesystem
+        // above it.
+        let outer = TempDir::new("root-clamp-outer");
+        let root = outer.path().join("root");
+        fs::create_dir_all(&root).unwrap();
+        fs::write(outer.path().join(".gitignore"), "root/\n").unwrap();
+
+        let ignore = Ignore::new(&root, true, false, true);
+
+        assert!(!ignore.is_ignored(&root, true));
+    }
+
+    #[test]
+    fn git_dir_is_excluded_by_default_but_can_be_opted_back_in() {
+        let root = TempDir::new("git-dir");
+        fs::create_dir_all(root.path().join(".git")).unwrap();
+        fs::write(root.path().join(".git/config"), "").unwrap();
+
+        let excluding = Ignore::new(root.path(), false, false, true);
+        assert!(excluding.is_ignored(&root.path().join(".git"), true));
+
+        let including = Ignore::new(root.path(), false, false, false);
+        assert!(!including.is_ignored(&root.path().join(".git"), true));
+    }
+}
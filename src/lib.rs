@@ -1,8 +1,16 @@
+mod binary;
+mod config;
+mod ignore;
+mod matcher;
+
+use binary::BinaryMode;
+use ignore::Ignore;
+use matcher::Matcher;
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 use rayon::prelude::*;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use walkdir::{DirEntry, WalkDir};
 
 #[napi(object)]
@@ -11,8 +19,26 @@ pub struct LingestOptions {
     pub output_path: String,
     pub ignore_globs: Vec<String>,
     pub include_globs: Vec<String>,
+    /// Only ingest files whose extension (without the leading `.`) is in this
+    /// list. Empty means no extension filtering. Composes with `include_globs`.
+    pub extensions: Vec<String>,
+    /// Never ingest files whose extension (without the leading `.`) is in this list.
+    pub exclude_extensions: Vec<String>,
     pub no_tree: bool,
     pub dry_run: bool,
+    /// How to handle files that look binary instead of UTF-8 text.
+    pub binary_mode: BinaryMode,
+    /// Honor nested `.gitignore`/`.ignore` files found while walking `cwd`,
+    /// in addition to `ignore_globs`.
+    pub respect_gitignore: bool,
+    /// Disable `.gitignore`/`.ignore` handling even when `respect_gitignore` is set.
+    /// This also turns off the default `.git/` exclusion.
+    pub no_ignore: bool,
+    /// `.git/` is excluded by default; set this to opt back in and walk it.
+    pub include_git_dir: bool,
+    /// Path to a `lingest.config` file to merge into `ignore_globs`/`include_globs`.
+    /// Defaults to `lingest.config` in `cwd` when not set.
+    pub config_path: Option<String>,
 }
 
 #[napi(object)]
@@ -33,28 +59,76 @@ pub struct FileContent {
 pub fn process_directory(options: LingestOptions) -> Result<LingestResult> {
     let cwd = Path::new(&options.cwd);
     let output_path = Path::new(&options.output_path);
-    
+
+    let config_path = match &options.config_path {
+        Some(path) => Some(PathBuf::from(path)),
+        None => Some(cwd.join("lingest.config")).filter(|p| p.exists()),
+    };
+    let parsed_config = config_path.map(|p| config::load(&p)).unwrap_or_default();
+    let ignore_globs = config::merge(parsed_config.ignore_globs, &options.ignore_globs);
+    let include_globs = config::merge(parsed_config.include_globs, &options.include_globs);
+
+    // For the flags and `binary_mode`, a caller-supplied, non-default value
+    // always wins; the `[options]` section only fills in values the caller
+    // left at their default. The extension lists are merged like the glob
+    // lists: config entries plus the caller's.
+    let respect_gitignore = options.respect_gitignore
+        || config_bool(&parsed_config.options, "respect_gitignore");
+    let no_ignore = options.no_ignore || config_bool(&parsed_config.options, "no_ignore");
+    let binary_mode = if options.binary_mode == BinaryMode::Skip {
+        config::option(&parsed_config.options, "binary_mode")
+            .and_then(parse_binary_mode)
+            .unwrap_or(BinaryMode::Skip)
+    } else {
+        options.binary_mode
+    };
+    let extensions = config::merge(
+        config::option(&parsed_config.options, "extensions")
+            .map(parse_csv_option)
+            .unwrap_or_default(),
+        &options.extensions,
+    );
+    let exclude_extensions = config::merge(
+        config::option(&parsed_config.options, "exclude_extensions")
+            .map(parse_csv_option)
+            .unwrap_or_default(),
+        &options.exclude_extensions,
+    );
+
+    let ignore = Ignore::new(cwd, respect_gitignore, no_ignore, !options.include_git_dir);
+    let ignore_matcher = Matcher::new(&ignore_globs);
+    let include_matcher = Matcher::new(&include_globs);
+
     // Generate tree if needed
     let tree = if !options.no_tree {
         Some(generate_tree(
             cwd,
             cwd,
             "",
-            &options.ignore_globs,
-            &options.include_globs,
+            &ignore_matcher,
+            &include_matcher,
+            include_globs.is_empty(),
+            &extensions,
+            &exclude_extensions,
             output_path,
+            &ignore,
         ))
     } else {
         None
     };
-    
+
     // Process file contents
     let (file_contents, processed_count) = process_files(
         cwd,
-        &options.ignore_globs,
-        &options.include_globs,
+        &ignore_matcher,
+        &include_matcher,
+        include_globs.is_empty(),
+        &extensions,
+        &exclude_extensions,
         output_path,
         options.dry_run,
+        &binary_mode,
+        &ignore,
     )?;
     
     Ok(LingestResult {
@@ -68,9 +142,13 @@ fn generate_tree(
     dir: &Path,
     base_path: &Path,
     prefix: &str,
-    ignore_globs: &[String],
-    include_globs: &[String],
+    ignore_matcher: &Matcher,
+    include_matcher: &Matcher,
+    include_all: bool,
+    extensions: &[String],
+    exclude_extensions: &[String],
     output_path: &Path,
+    ignore: &Ignore,
 ) -> String {
     let mut tree = String::new();
     
@@ -100,33 +178,45 @@ fn generate_tree(
         let relative_path = path.strip_prefix(base_path).unwrap_or(&path);
         let relative_str = relative_path.to_string_lossy().replace('\\', "/");
         
-        if should_ignore(&relative_str, ignore_globs) {
+        if ignore_matcher.is_match(&relative_str) {
             continue;
         }
-        
+
+        let is_dir = entry.file_type().map(|t| t.is_dir()).unwrap_or(false);
+        if ignore.is_ignored(&path, is_dir) {
+            continue;
+        }
+
         let is_last = index == total - 1;
         let connector = if is_last { "└── " } else { "├── " };
-        
+
         let file_name = entry.file_name();
         let name = file_name.to_string_lossy();
-        
-        if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+
+        if is_dir {
             tree.push_str(prefix);
             tree.push_str(connector);
             tree.push_str(&name);
             tree.push_str("/\n");
-            
+
             let new_prefix = format!("{}{}", prefix, if is_last { "    " } else { "│   " });
             tree.push_str(&generate_tree(
                 &path,
                 base_path,
                 &new_prefix,
-                ignore_globs,
-                include_globs,
+                ignore_matcher,
+                include_matcher,
+                include_all,
+                extensions,
+                exclude_extensions,
                 output_path,
+                ignore,
             ));
         } else if entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
-            if !include_globs.is_empty() && !matches_any(&relative_str, include_globs) {
+            if !include_all && !include_matcher.is_match(&relative_str) {
+                continue;
+            }
+            if !extension_allowed(&relative_str, extensions, exclude_extensions) {
                 continue;
             }
             tree.push_str(prefix);
@@ -141,43 +231,78 @@ fn generate_tree(
 
 fn process_files(
     base_path: &Path,
-    ignore_globs: &[String],
-    include_globs: &[String],
+    ignore_matcher: &Matcher,
+    include_matcher: &Matcher,
+    include_all: bool,
+    extensions: &[String],
+    exclude_extensions: &[String],
     output_path: &Path,
     dry_run: bool,
+    binary_mode: &BinaryMode,
+    ignore: &Ignore,
 ) -> Result<(Vec<FileContent>, u32)> {
     let walker = WalkDir::new(base_path)
         .follow_links(false)
         .into_iter()
+        .filter_entry(|e| !ignore.is_ignored(e.path(), e.file_type().is_dir()))
         .filter_map(|e| e.ok())
         .filter(|e| e.file_type().is_file())
         .filter(|e| e.path() != output_path);
-    
+
     let entries: Vec<DirEntry> = walker.collect();
-    
+
     let file_contents: Vec<FileContent> = entries
         .par_iter()
         .filter_map(|entry| {
             let path = entry.path();
             let relative_path = path.strip_prefix(base_path).unwrap_or(path);
             let relative_str = relative_path.to_string_lossy().replace('\\', "/");
-            
+
             // Check ignore patterns
-            if should_ignore(&relative_str, ignore_globs) {
+            if ignore_matcher.is_match(&relative_str) {
                 return None;
             }
-            
+
             // Check include patterns
-            if !include_globs.is_empty() && !matches_any(&relative_str, include_globs) {
+            if !include_all && !include_matcher.is_match(&relative_str) {
+                return None;
+            }
+
+            // Check extension filters
+            if !extension_allowed(&relative_str, extensions, exclude_extensions) {
                 return None;
             }
-            
+
             if dry_run {
                 Some(FileContent {
                     path: relative_str.clone(),
                     content: format!("[Dry Run] Content of {} would be here.", relative_str),
                     error: None,
                 })
+            } else if binary::looks_binary(path) {
+                match binary_mode {
+                    BinaryMode::Skip => None,
+                    BinaryMode::Placeholder => {
+                        let size = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+                        Some(FileContent {
+                            path: relative_str,
+                            content: format!("[Binary file, {} bytes]", size),
+                            error: None,
+                        })
+                    }
+                    BinaryMode::Base64 => match binary::read_base64(path) {
+                        Ok(content) => Some(FileContent {
+                            path: relative_str,
+                            content,
+                            error: None,
+                        }),
+                        Err(_) => Some(FileContent {
+                            path: relative_str,
+                            content: String::new(),
+                            error: Some("Could not be read.".to_string()),
+                        }),
+                    },
+                }
             } else {
                 match fs::read_to_string(path) {
                     Ok(content) => Some(FileContent {
@@ -196,26 +321,53 @@ fn process_files(
         .collect();
     
     let processed_count = file_contents.len() as u32;
-    
+
     Ok((file_contents, processed_count))
 }
 
-fn should_ignore(path: &str, patterns: &[String]) -> bool {
-    patterns.iter().any(|pattern| {
-        glob::Pattern::new(pattern)
-            .map(|p| p.matches(path))
-            .unwrap_or(false)
-    })
+/// A file passes only if its extension is in `extensions` (when non-empty)
+/// and not in `exclude_extensions`.
+fn extension_allowed(relative_str: &str, extensions: &[String], exclude_extensions: &[String]) -> bool {
+    let ext = matcher::file_extension(relative_str);
+
+    if !extensions.is_empty() {
+        match ext {
+            Some(ext) if extensions.iter().any(|e| e == ext) => {}
+            _ => return false,
+        }
+    }
+
+    if let Some(ext) = ext {
+        if exclude_extensions.iter().any(|e| e == ext) {
+            return false;
+        }
+    }
+
+    true
 }
 
-fn matches_any(path: &str, patterns: &[String]) -> bool {
-    patterns.iter().any(|pattern| {
-        glob::Pattern::new(pattern)
-            .map(|p| p.matches(path))
-            .unwrap_or(false)
-    })
+/// Parses a `[options]` value as a boolean; anything unparseable is `false`.
+fn config_bool(options: &[(String, String)], key: &str) -> bool {
+    config::option(options, key)
+        .map(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "true" | "1" | "yes"))
+        .unwrap_or(false)
+}
+
+/// Parses a `[options]` value as a comma-separated list (e.g. `js,ts,rs`).
+fn parse_csv_option(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+fn parse_binary_mode(value: &str) -> Option<BinaryMode> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "skip" => Some(BinaryMode::Skip),
+        "placeholder" => Some(BinaryMode::Placeholder),
+        "base64" => Some(BinaryMode::Base64),
+        _ => None,
+    }
 }
 
-// Note: This is a placeholder comment. The ignore patterns are actually passed from JavaScript
-// to Rust via the options parameter, so we don't need to duplicate the list here.
-// The patterns defined in index.js will be used by the Rust code. 
\ No newline at end of file
@@ -0,0 +1,132 @@
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::collections::{HashMap, HashSet};
+
+/// Compiles a list of user-supplied glob patterns once and answers
+/// `is_match` queries against the compiled form, instead of recompiling
+/// every pattern for every file (following ripgrep's glob-set design).
+///
+/// Patterns are partitioned at build time into three buckets, checked in
+/// order from cheapest to most general:
+/// 1. literal paths with no metacharacters, for O(1) exact lookup
+/// 2. pure extension patterns (`*.rs`), keyed by the suffix after the last `.`
+/// 3. everything else, compiled into a single `GlobSet`
+pub struct Matcher {
+    literals: HashSet<String>,
+    extensions: HashMap<String, ()>,
+    glob_set: GlobSet,
+}
+
+impl Matcher {
+    pub fn new(patterns: &[String]) -> Matcher {
+        let mut literals = HashSet::new();
+        let mut extensions = HashMap::new();
+        let mut builder = GlobSetBuilder::new();
+
+        for pattern in patterns {
+            if let Some(ext) = pure_extension_pattern(pattern) {
+                extensions.insert(ext.to_string(), ());
+            } else if is_literal(pattern) {
+                literals.insert(pattern.clone());
+            } else if let Ok(glob) = Glob::new(pattern) {
+                builder.add(glob);
+            }
+        }
+
+        let glob_set = builder.build().unwrap_or_else(|_| GlobSet::empty());
+
+        Matcher {
+            literals,
+            extensions,
+            glob_set,
+        }
+    }
+
+    pub fn is_match(&self, path: &str) -> bool {
+        if self.literals.contains(path) {
+            return true;
+        }
+
+        if !self.extensions.is_empty() {
+            if let Some(ext) = file_extension(path) {
+                if self.extensions.contains_key(ext) {
+                    return true;
+                }
+            }
+        }
+
+        self.glob_set.is_match(path)
+    }
+}
+
+/// Returns the extension matched by a pattern of the exact shape `*.ext`,
+/// i.e. a pattern with no other metacharacters or path separators.
+///
+/// Patterns in the extension bucket match at any depth (e.g. `src/a.rs`).
+/// That is the same result the `GlobSet` bucket gives: its globs are built
+/// with the default options, under which `*` also matches `/`.
+/// Multi-dot patterns like `*.tar.gz` or `*.d.ts` are rejected here (the
+/// captured ext would be `"tar.gz"`/`"d.ts"`, but `file_extension` keys on
+/// the suffix after the *last* dot, e.g. `"gz"`/`"ts"` — they'd never
+/// match). Those fall through to the `GlobSet` bucket instead, which
+/// matches them at any depth as well.
+fn pure_extension_pattern(pattern: &str) -> Option<&str> {
+    let ext = pattern.strip_prefix("*.")?;
+    if ext.is_empty() || ext.contains(['*', '?', '[', ']', '{', '}', '\\', '/', '!', '.']) {
+        None
+    } else {
+        Some(ext)
+    }
+}
+
+fn is_literal(pattern: &str) -> bool {
+    !pattern.contains(['*', '?', '[', ']', '{', '}', '\\', '!'])
+}
+
+/// Returns the extension of `path`'s final component (after the last `.`),
+/// mirroring `std::path::Path::extension`: a dotfile like `.gitignore` has
+/// no extension, and the extension is taken from the file name only, not
+/// from any dot that happens to appear in a parent directory's name.
+pub fn file_extension(path: &str) -> Option<&str> {
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    let mut parts = file_name.rsplitn(2, '.');
+    let ext = parts.next()?;
+    let stem = parts.next()?;
+    if stem.is_empty() {
+        None
+    } else {
+        Some(ext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_extension_ignores_dots_in_parent_dirs() {
+        assert_eq!(file_extension("app.v2/Makefile"), None);
+        assert_eq!(file_extension("src/a.rs"), Some("rs"));
+    }
+
+    #[test]
+    fn file_extension_treats_dotfiles_as_extensionless() {
+        assert_eq!(file_extension(".gitignore"), None);
+        assert_eq!(file_extension(".env.local"), Some("local"));
+    }
+
+    #[test]
+    fn extension_pattern_matches_at_any_depth() {
+        let matcher = Matcher::new(&["*.rs".to_string()]);
+        assert!(matcher.is_match("a.rs"));
+        assert!(matcher.is_match("src/a.rs"));
+        assert!(!matcher.is_match("src/a.ts"));
+    }
+
+    #[test]
+    fn multi_dot_patterns_match_via_glob_set() {
+        let matcher = Matcher::new(&["*.tar.gz".to_string(), "*.d.ts".to_string()]);
+        assert!(matcher.is_match("archive.tar.gz"));
+        assert!(matcher.is_match("types.d.ts"));
+        assert!(!matcher.is_match("archive.gz"));
+    }
+}